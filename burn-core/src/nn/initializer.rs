@@ -1,9 +1,9 @@
-use burn_tensor::Shape;
-use libm::sqrt;
+use burn_tensor::{ElementConversion, Shape};
+use libm::{erf, sqrt};
 
 use crate::config::Config;
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, Tensor};
+use crate::tensor::{Data, Distribution, Tensor};
 
 use crate as burn;
 
@@ -65,6 +65,83 @@ pub enum Initializer {
         /// The gain to use in initialization formula
         gain: f64,
     },
+    /// Fills tensor with a (semi) orthogonal matrix, as described in [Exact solutions to the
+    /// nonlinear dynamics of learning in deep linear neural networks
+    /// ](https://arxiv.org/abs/1312.6120). The tensor must have at least 2 dimensions.
+    Orthogonal {
+        /// The multiplicative factor to apply to the orthogonal matrix
+        gain: f64,
+    },
+    /// Fills tensor with values drawn from a normal distribution truncated to `[a, b]`, the
+    /// standard weight initialization for transformer and ViT architectures.
+    TruncatedNormal {
+        /// The mean of the underlying normal distribution
+        mean: f64,
+
+        /// The standard deviation of the underlying normal distribution
+        std: f64,
+
+        /// The lower bound of the truncation interval
+        a: f64,
+
+        /// The upper bound of the truncation interval
+        b: f64,
+    },
+    /// Fills a 2-D tensor from `Normal(0, std)` and then zeros out a `sparsity` fraction of the
+    /// rows in every column, so most connections start dead.
+    Sparse {
+        /// The standard deviation of the values in the non-zero entries
+        std: f64,
+
+        /// The fraction of rows to zero out in each column
+        sparsity: f64,
+    },
+    /// Fills a 2-D tensor as an identity matrix, preserving the inputs of a linear layer.
+    Eye,
+    /// Fills a 3/4/5-D convolution weight so the convolution acts as an identity mapping, using
+    /// the given number of `groups`.
+    Dirac {
+        /// The number of groups in the (grouped) convolution
+        groups: usize,
+    },
+    /// Fills a 4-D transposed-convolution weight with a bilinear interpolation kernel, the
+    /// standard way to initialize learned upsampling so it starts as smooth image resizing.
+    /// Intended for `ConvTranspose2d` weights of shape `[out_channels, in_channels, kh, kw]`.
+    Bilinear,
+}
+
+/// The non-linearity that a fan-scaled initializer feeds into, used to look up the recommended
+/// gain through [`gain_for`] instead of passing a bare magic constant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NonLinearity {
+    /// Identity activation.
+    Linear,
+    /// Sigmoid activation.
+    Sigmoid,
+    /// Hyperbolic tangent activation.
+    Tanh,
+    /// Rectified linear unit.
+    ReLU,
+    /// Leaky rectified linear unit with the given negative slope.
+    LeakyReLU {
+        /// The slope applied to negative inputs.
+        negative_slope: f64,
+    },
+    /// Scaled exponential linear unit.
+    SELU,
+}
+
+/// Returns the recommended gain for the given non-linearity, matching PyTorch's `calculate_gain`.
+pub fn gain_for(nonlinearity: NonLinearity) -> f64 {
+    match nonlinearity {
+        NonLinearity::Linear | NonLinearity::Sigmoid => 1.0,
+        NonLinearity::Tanh => 5.0 / 3.0,
+        NonLinearity::ReLU => sqrt(2.0),
+        NonLinearity::LeakyReLU { negative_slope } => {
+            sqrt(2.0 / (1.0 + negative_slope * negative_slope))
+        }
+        NonLinearity::SELU => 3.0 / 4.0,
+    }
 }
 
 impl Initializer {
@@ -92,6 +169,13 @@ impl Initializer {
         fan_out: Option<usize>,
     ) -> Tensor<B, D> {
         let shape = shape.into();
+
+        // Fall back to the fans inferred from the weight shape when the caller doesn't supply
+        // them, so fan-scaled initializers work directly on a layer's weight.
+        let (fan_in_inferred, fan_out_inferred) = calculate_fans(&shape);
+        let fan_in = fan_in.or(Some(fan_in_inferred));
+        let fan_out = fan_out.or(Some(fan_out_inferred));
+
         match self {
             Initializer::Constant { value } => Tensor::<B, D>::full(shape, *value),
             Initializer::Ones => Tensor::<B, D>::ones(shape),
@@ -114,9 +198,62 @@ impl Initializer {
                 let std = *gain * self.xavier_std(fan_in, fan_out);
                 normal_draw(shape, 0.0, std)
             }
+            Initializer::Orthogonal { gain } => orthogonal_draw(shape, *gain),
+            Initializer::TruncatedNormal { mean, std, a, b } => {
+                trunc_normal_draw(shape, *mean, *std, *a, *b)
+            }
+            Initializer::Sparse { std, sparsity } => sparse_draw(shape, *std, *sparsity),
+            Initializer::Eye => eye_draw(shape),
+            Initializer::Dirac { groups } => dirac_draw(shape, *groups),
+            Initializer::Bilinear => bilinear_draw(shape),
+        }
+    }
+
+    /// Builds a [`Initializer::KaimingUniform`] whose gain is derived from the non-linearity the
+    /// weight feeds into, so the intent ("this feeds a ReLU") is expressed instead of a constant.
+    pub fn kaiming_uniform(nonlinearity: NonLinearity, fan_out_only: bool) -> Self {
+        Initializer::KaimingUniform {
+            gain: gain_for(nonlinearity),
+            fan_out_only,
+        }
+    }
+
+    /// Builds a [`Initializer::KaimingNormal`] with the gain recommended for `nonlinearity`.
+    pub fn kaiming_normal(nonlinearity: NonLinearity, fan_out_only: bool) -> Self {
+        Initializer::KaimingNormal {
+            gain: gain_for(nonlinearity),
+            fan_out_only,
         }
     }
 
+    /// Builds a [`Initializer::XavierUniform`] with the gain recommended for `nonlinearity`.
+    pub fn xavier_uniform(nonlinearity: NonLinearity) -> Self {
+        Initializer::XavierUniform {
+            gain: gain_for(nonlinearity),
+        }
+    }
+
+    /// Builds a [`Initializer::XavierNormal`] with the gain recommended for `nonlinearity`.
+    pub fn xavier_normal(nonlinearity: NonLinearity) -> Self {
+        Initializer::XavierNormal {
+            gain: gain_for(nonlinearity),
+        }
+    }
+
+    /// Returns a freshly initialized tensor sharing the shape and device of `tensor`.
+    ///
+    /// This lets modules reset an already-constructed parameter (for example re-running weight
+    /// init on a loaded generator) without restating its shape and device by hand. Fans are
+    /// inferred from the tensor shape. The input is consumed and a new tensor is returned.
+    ///
+    /// # Params
+    ///
+    /// - tensor: The tensor whose shape and device the new values are drawn from.
+    pub fn init_into<B: Backend, const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let device = tensor.device();
+        self.init_with(tensor.shape(), None, None).to_device(&device)
+    }
+
     fn kaiming_std(
         &self,
         fan_out_only: bool,
@@ -144,6 +281,23 @@ impl Initializer {
     }
 }
 
+/// Derives `(fan_in, fan_out)` from a weight shape the way PyTorch and Paddle do.
+///
+/// For `D < 2` both fans equal the number of elements; for a `D == 2` linear weight
+/// `fan_in = dims[1]` and `fan_out = dims[0]`; for a conv kernel (`D >= 3`) the trailing
+/// dimensions form the receptive field that scales both fans.
+fn calculate_fans<const D: usize>(shape: &Shape<D>) -> (usize, usize) {
+    if D < 2 {
+        let numel = shape.num_elements();
+        return (numel, numel);
+    }
+
+    let receptive_field: usize = shape.dims[2..].iter().product();
+    let fan_in = shape.dims[1] * receptive_field;
+    let fan_out = shape.dims[0] * receptive_field;
+    (fan_in, fan_out)
+}
+
 fn uniform_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
     shape: S,
     low: f64,
@@ -162,6 +316,351 @@ fn normal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
     Tensor::<B, D>::random(shape, distribution)
 }
 
+fn orthogonal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
+    shape: S,
+    gain: f64,
+) -> Tensor<B, D> {
+    let shape = shape.into();
+    assert!(
+        D >= 2,
+        "Orthogonal initialization requires a tensor with at least 2 dimensions, got {D}."
+    );
+
+    let rows = shape.dims[0];
+    let cols = shape.num_elements() / rows;
+
+    // Draw a random normal matrix and factorize it with the larger axis as rows, so that the
+    // columns of Q are orthonormal, transposing the result back afterwards when needed.
+    let (n, m, transposed) = if rows < cols {
+        (cols, rows, true)
+    } else {
+        (rows, cols, false)
+    };
+
+    let flat: Tensor<B, 2> = normal_draw([rows, cols], 0.0, 1.0);
+    let flat: Vec<f64> = flat.into_data().value.iter().map(|e| e.elem()).collect();
+
+    let mut mat = vec![0.0; n * m];
+    for r in 0..rows {
+        for c in 0..cols {
+            let v = flat[r * cols + c];
+            if transposed {
+                mat[c * m + r] = v;
+            } else {
+                mat[r * m + c] = v;
+            }
+        }
+    }
+
+    let (q, diag_r) = qr_reduced(&mat, n, m);
+
+    let mut result = vec![0.0; rows * cols];
+    for r in 0..rows {
+        for c in 0..cols {
+            // `sign(diag(R))` makes the factorization sign-stable (and hence deterministic).
+            let (qi, qj) = if transposed { (c, r) } else { (r, c) };
+            let sign = if diag_r[qj] < 0.0 { -1.0 } else { 1.0 };
+            result[r * cols + c] = gain * sign * q[qi * m + qj];
+        }
+    }
+
+    let data = Data::new(result.into_iter().map(|v| v.elem()).collect(), shape);
+    Tensor::<B, D>::from_data(data)
+}
+
+fn sparse_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
+    shape: S,
+    std: f64,
+    sparsity: f64,
+) -> Tensor<B, D> {
+    let shape = shape.into();
+    assert_eq!(
+        D, 2,
+        "Sparse initialization is only defined for 2-dimensional tensors, got {D}."
+    );
+
+    let rows = shape.dims[0];
+    let cols = shape.dims[1];
+    let num_zeros = (sparsity * rows as f64).round() as usize;
+
+    let mut values: Vec<f64> = normal_draw::<B, D, _>(shape.clone(), 0.0, std)
+        .into_data()
+        .value
+        .iter()
+        .map(|e| e.elem())
+        .collect();
+
+    // Use a second random draw as sort keys so the zeroed rows are chosen from the backend RNG.
+    let keys: Vec<f64> = uniform_draw::<B, D, _>(shape.clone(), 0.0, 1.0)
+        .into_data()
+        .value
+        .iter()
+        .map(|e| e.elem())
+        .collect();
+
+    for col in 0..cols {
+        let mut rows_idx: Vec<usize> = (0..rows).collect();
+        rows_idx.sort_by(|&a, &b| {
+            keys[a * cols + col]
+                .partial_cmp(&keys[b * cols + col])
+                .unwrap()
+        });
+        for &row in rows_idx.iter().take(num_zeros) {
+            values[row * cols + col] = 0.0;
+        }
+    }
+
+    let data = Data::new(values.into_iter().map(|v| v.elem()).collect(), shape);
+    Tensor::<B, D>::from_data(data)
+}
+
+fn eye_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(shape: S) -> Tensor<B, D> {
+    let shape = shape.into();
+    assert_eq!(
+        D, 2,
+        "Eye initialization is only defined for 2-dimensional tensors, got {D}."
+    );
+
+    let rows = shape.dims[0];
+    let cols = shape.dims[1];
+    let mut values = vec![0.0f64; rows * cols];
+    for i in 0..rows.min(cols) {
+        values[i * cols + i] = 1.0;
+    }
+
+    let data = Data::new(values.into_iter().map(|v| v.elem()).collect(), shape);
+    Tensor::<B, D>::from_data(data)
+}
+
+fn dirac_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
+    shape: S,
+    groups: usize,
+) -> Tensor<B, D> {
+    let shape = shape.into();
+    assert!(
+        (3..=5).contains(&D),
+        "Dirac initialization is only defined for 3, 4 or 5-dimensional tensors, got {D}."
+    );
+
+    let out_channels = shape.dims[0];
+    let in_channels = shape.dims[1];
+    assert_eq!(
+        out_channels % groups,
+        0,
+        "The number of output channels ({out_channels}) must be divisible by groups ({groups})."
+    );
+
+    let spatial: usize = shape.dims[2..].iter().product();
+    let out_per_group = out_channels / groups;
+    let min_dim = out_per_group.min(in_channels);
+
+    // Offset of the center spatial position inside the flattened receptive field.
+    let mut center = 0;
+    let mut stride = 1;
+    for &dim in shape.dims[2..].iter().rev() {
+        center += (dim / 2) * stride;
+        stride *= dim;
+    }
+
+    let mut values = vec![0.0f64; shape.num_elements()];
+    for g in 0..groups {
+        for d in 0..min_dim {
+            let out = g * out_per_group + d;
+            let flat = (out * in_channels + d) * spatial + center;
+            values[flat] = 1.0;
+        }
+    }
+
+    let data = Data::new(values.into_iter().map(|v| v.elem()).collect(), shape);
+    Tensor::<B, D>::from_data(data)
+}
+
+fn bilinear_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(shape: S) -> Tensor<B, D> {
+    let shape = shape.into();
+    assert_eq!(
+        D, 4,
+        "Bilinear initialization is only defined for 4-dimensional tensors, got {D}."
+    );
+
+    let out_channels = shape.dims[0];
+    let in_channels = shape.dims[1];
+    let kh = shape.dims[2];
+    let kw = shape.dims[3];
+
+    // `factor`/`center` are computed independently per spatial axis (they coincide when kh == kw).
+    let factor_h = (kh + 1) / 2;
+    let factor_w = (kw + 1) / 2;
+    let center_h = (kh - 1) as f64 / (2.0 * factor_h as f64);
+    let center_w = (kw - 1) as f64 / (2.0 * factor_w as f64);
+
+    let mut kernel = vec![0.0f64; kh * kw];
+    for i in 0..kh {
+        for j in 0..kw {
+            let vh = 1.0 - (i as f64 / factor_h as f64 - center_h).abs();
+            let vw = 1.0 - (j as f64 / factor_w as f64 - center_w).abs();
+            kernel[i * kw + j] = vh * vw;
+        }
+    }
+
+    // Broadcast the kernel across the diagonal channel pairs, leaving off-diagonal pairs at zero.
+    let mut values = vec![0.0f64; shape.num_elements()];
+    for c in 0..out_channels.min(in_channels) {
+        let base = (c * in_channels + c) * kh * kw;
+        values[base..base + kh * kw].copy_from_slice(&kernel);
+    }
+
+    let data = Data::new(values.into_iter().map(|v| v.elem()).collect(), shape);
+    Tensor::<B, D>::from_data(data)
+}
+
+fn trunc_normal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
+    shape: S,
+    mean: f64,
+    std: f64,
+    a: f64,
+    b: f64,
+) -> Tensor<B, D> {
+    if mean < a - 2.0 * std || mean > b + 2.0 * std {
+        log::warn!(
+            "TruncatedNormal: mean ({mean}) is more than two std ({std}) outside [{a}, {b}]; the \
+             resulting distribution is inaccurate."
+        );
+    }
+
+    let shape = shape.into();
+
+    // Inverse-CDF sampling: draw uniformly in [2l - 1, 2u - 1] (the erf domain of the truncated
+    // cumulative probabilities) then map back through the inverse error function.
+    let phi = |x: f64| 0.5 * (1.0 + erf(x / sqrt(2.0)));
+    let l = phi((a - mean) / std);
+    let u = phi((b - mean) / std);
+
+    let uniform: Tensor<B, D> = uniform_draw(shape.clone(), 2.0 * l - 1.0, 2.0 * u - 1.0);
+
+    let values: Vec<B::FloatElem> = uniform
+        .into_data()
+        .value
+        .iter()
+        .map(|e| {
+            let x = mean + std * sqrt(2.0) * erf_inv(e.elem());
+            x.clamp(a, b).elem()
+        })
+        .collect();
+
+    Tensor::<B, D>::from_data(Data::new(values, shape))
+}
+
+/// Inverse of the error function, using the rational approximation from Giles (2010),
+/// "Approximating the erfinv function". Accurate to roughly single-precision over `(-1, 1)`.
+fn erf_inv(x: f64) -> f64 {
+    let mut w = -libm::log((1.0 - x) * (1.0 + x));
+    let p;
+    if w < 5.0 {
+        w -= 2.5;
+        p = 2.810_226_36e-08;
+        let p = 3.432_739_39e-07 + p * w;
+        let p = -3.523_387_7e-06 + p * w;
+        let p = -4.391_506_54e-06 + p * w;
+        let p = 0.000_218_580_87 + p * w;
+        let p = -0.001_253_725_03 + p * w;
+        let p = -0.004_177_681_64 + p * w;
+        let p = 0.246_640_727 + p * w;
+        let p = 1.501_409_41 + p * w;
+        p * x
+    } else {
+        w = sqrt(w) - 3.0;
+        p = -0.000_200_214_257;
+        let p = 0.000_100_950_558 + p * w;
+        let p = 0.001_349_343_22 + p * w;
+        let p = -0.003_673_428_44 + p * w;
+        let p = 0.005_739_507_73 + p * w;
+        let p = -0.007_622_461_3 + p * w;
+        let p = 0.009_438_870_47 + p * w;
+        let p = 1.001_674_06 + p * w;
+        let p = 2.832_976_82 + p * w;
+        p * x
+    }
+}
+
+/// Reduced QR factorization of a row-major `n x m` matrix (with `n >= m`) using Householder
+/// reflections. Returns the orthonormal factor `Q` (row-major `n x m`) along with the diagonal of
+/// `R`, which is used to make the decomposition sign-stable.
+fn qr_reduced(a: &[f64], n: usize, m: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut r = a.to_vec();
+    let mut q = vec![0.0; n * n];
+    for i in 0..n {
+        q[i * n + i] = 1.0;
+    }
+
+    let mut diag_r = vec![0.0; m];
+    let mut v = vec![0.0; n];
+
+    for k in 0..m {
+        let mut norm = 0.0;
+        for i in k..n {
+            norm += r[i * m + k] * r[i * m + k];
+        }
+        let norm = sqrt(norm);
+
+        if norm == 0.0 {
+            diag_r[k] = 0.0;
+            continue;
+        }
+
+        let alpha = if r[k * m + k] > 0.0 { -norm } else { norm };
+
+        for i in k..n {
+            v[i] = r[i * m + k];
+        }
+        v[k] -= alpha;
+
+        let mut vnorm = 0.0;
+        for i in k..n {
+            vnorm += v[i] * v[i];
+        }
+        if vnorm == 0.0 {
+            diag_r[k] = r[k * m + k];
+            continue;
+        }
+
+        // Apply H = I - 2 v vᵀ / (vᵀv) to the trailing columns of R.
+        for j in k..m {
+            let mut dot = 0.0;
+            for i in k..n {
+                dot += v[i] * r[i * m + j];
+            }
+            let scale = 2.0 * dot / vnorm;
+            for i in k..n {
+                r[i * m + j] -= scale * v[i];
+            }
+        }
+
+        // Accumulate the reflection into Q (Q <- Q H).
+        for row in 0..n {
+            let mut dot = 0.0;
+            for i in k..n {
+                dot += q[row * n + i] * v[i];
+            }
+            let scale = 2.0 * dot / vnorm;
+            for i in k..n {
+                q[row * n + i] -= scale * v[i];
+            }
+        }
+
+        diag_r[k] = r[k * m + k];
+    }
+
+    // Keep the first m columns of Q for the reduced factorization.
+    let mut q_reduced = vec![0.0; n * m];
+    for row in 0..n {
+        for col in 0..m {
+            q_reduced[row * m + col] = q[row * n + col];
+        }
+    }
+
+    (q_reduced, diag_r)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,18 +813,37 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn initializer_kaiming_uniform_no_fan() {
+    fn initializer_kaiming_uniform_infers_fan() {
         TB::seed(0);
 
         let gain = 2_f64;
         let (fan_in, fan_out) = (5, 6);
+        let k = gain * sqrt(3.0 / fan_in as f64);
 
-        let _: Tensor<TB, 2> = Initializer::KaimingUniform {
+        // The fan is inferred straight from the weight shape [fan_out, fan_in].
+        let tensor: Tensor<TB, 2> = Initializer::KaimingUniform {
             gain,
             fan_out_only: false,
         }
         .init([fan_out, fan_in]);
+        tensor.into_data().assert_within_range(-k..k);
+    }
+
+    #[test]
+    fn initializer_kaiming_uniform_infers_conv_fan() {
+        TB::seed(0);
+
+        let gain = 2_f64;
+        let (out_channels, in_channels, kh, kw) = (64, 32, 3, 3);
+        let fan_in = in_channels * kh * kw;
+        let k = gain * sqrt(3.0 / fan_in as f64);
+
+        let tensor: Tensor<TB, 4> = Initializer::KaimingUniform {
+            gain,
+            fan_out_only: false,
+        }
+        .init([out_channels, in_channels, kh, kw]);
+        tensor.into_data().assert_within_range(-k..k);
     }
 
     #[test]
@@ -361,13 +879,152 @@ mod tests {
         assert_normal_init(expected_mean, expected_var, &tensor)
     }
 
+    #[test]
+    fn initializer_orthogonal_is_semi_orthogonal() {
+        TB::seed(0);
+
+        let (rows, cols) = (6, 4);
+        let tensor: Tensor<TB, 2> = Initializer::Orthogonal { gain: 1.0 }.init([rows, cols]);
+
+        // With rows >= cols the columns are orthonormal, so QᵀQ = I.
+        let gram = tensor.clone().transpose().matmul(tensor);
+
+        let mut eye = vec![0.0f32; cols * cols];
+        for i in 0..cols {
+            eye[i * cols + i] = 1.0;
+        }
+        let identity = Data::new(eye, Shape::new([cols, cols]));
+
+        gram.into_data().assert_approx_eq(&identity, 3);
+    }
+
+    #[test]
+    fn gain_for_matches_reference_values() {
+        assert_eq!(gain_for(NonLinearity::Linear), 1.0);
+        assert_eq!(gain_for(NonLinearity::Sigmoid), 1.0);
+        assert_eq!(gain_for(NonLinearity::Tanh), 5.0 / 3.0);
+        assert_eq!(gain_for(NonLinearity::ReLU), sqrt(2.0));
+        assert_eq!(
+            gain_for(NonLinearity::LeakyReLU {
+                negative_slope: 0.0
+            }),
+            sqrt(2.0)
+        );
+        assert_eq!(gain_for(NonLinearity::SELU), 3.0 / 4.0);
+    }
+
+    #[test]
+    fn kaiming_uniform_from_nonlinearity_sets_gain() {
+        assert_eq!(
+            Initializer::kaiming_uniform(NonLinearity::ReLU, false),
+            Initializer::KaimingUniform {
+                gain: sqrt(2.0),
+                fan_out_only: false,
+            }
+        );
+    }
+
+    #[test]
+    fn initializer_init_into_reuses_shape() {
+        let existing: Tensor<TB, 2> = Tensor::zeros([4, 3]);
+        let reinit = Initializer::Ones.init_into(existing);
+
+        assert_eq!(reinit.shape().dims, [4, 3]);
+        reinit
+            .sum()
+            .to_data()
+            .assert_approx_eq(&Data::from([12.0]), 3);
+    }
+
+    #[test]
+    fn initializer_eye_init() {
+        let tensor: Tensor<TB, 2> = Initializer::Eye.init([3, 3]);
+
+        let identity = Data::from([
+            [1.0f32, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ]);
+        tensor.into_data().assert_approx_eq(&identity, 3);
+    }
+
+    #[test]
+    fn initializer_bilinear_init() {
+        // A 4x4 bilinear kernel peaks at its center and is symmetric.
+        let tensor: Tensor<TB, 4> = Initializer::Bilinear.init([1, 1, 4, 4]);
+        let values = tensor.into_data().value;
+
+        let kernel: Vec<f32> = values.iter().map(|e| e.elem::<f32>()).collect();
+        // Center taps carry the most weight.
+        assert!(kernel[5] > kernel[0]);
+        // Horizontally symmetric within a row.
+        assert!((kernel[0] - kernel[3]).abs() < 1e-6);
+        assert!((kernel[4] - kernel[7]).abs() < 1e-6);
+    }
+
+    #[test]
+    fn initializer_dirac_init() {
+        // A Dirac 3x3 kernel leaves the two input channels untouched at the center position.
+        let tensor: Tensor<TB, 4> = Initializer::Dirac { groups: 1 }.init([2, 2, 3, 3]);
+
+        tensor
+            .clone()
+            .sum()
+            .to_data()
+            .assert_approx_eq(&Data::from([2.0]), 3);
+
+        let values = tensor.into_data().value;
+        // Center of a 3x3 kernel is index 4; diagonal channel pairs (0,0) and (1,1) carry the 1s.
+        assert_eq!(values[4].elem::<f32>(), 1.0);
+        assert_eq!(values[31].elem::<f32>(), 1.0);
+    }
+
+    #[test]
+    fn initializer_sparse_init() {
+        TB::seed(0);
+
+        let (rows, cols) = (20, 5);
+        let sparsity = 0.5;
+        let tensor: Tensor<TB, 2> = Initializer::Sparse { std: 1.0, sparsity }.init([rows, cols]);
+
+        let values = tensor.into_data().value;
+        for col in 0..cols {
+            let zeros = (0..rows)
+                .filter(|&row| values[row * cols + col].elem::<f32>() == 0.0)
+                .count();
+            assert_eq!(zeros, (sparsity * rows as f64).round() as usize);
+        }
+    }
+
+    #[test]
+    fn initializer_truncated_normal_init() {
+        TB::seed(0);
+
+        let (mean, std, a, b) = (0.0, 1.0, -2.0, 2.0);
+        let tensor: Tensor<TB, 1> = Initializer::TruncatedNormal { mean, std, a, b }.init([1000]);
+
+        // Clamping can produce values exactly at the bounds, so widen the range slightly.
+        tensor
+            .into_data()
+            .assert_within_range(a as f32 - 0.01..b as f32 + 0.01);
+    }
+
     #[test]
     #[should_panic]
-    fn initializer_xavier_uniform_no_fan() {
+    fn initializer_orthogonal_1d_panics() {
+        let _: Tensor<TB, 1> = Initializer::Orthogonal { gain: 1.0 }.init([4]);
+    }
+
+    #[test]
+    fn initializer_xavier_uniform_infers_fan() {
         TB::seed(0);
 
         let gain = 2.;
         let (fan_in, fan_out) = (5, 6);
-        let _: Tensor<TB, 2> = Initializer::XavierUniform { gain }.init([fan_out, fan_in]);
+        let bound = gain * sqrt(6. / (fan_in + fan_out) as f64);
+
+        // Both fans are inferred from the weight shape [fan_out, fan_in].
+        let tensor: Tensor<TB, 2> = Initializer::XavierUniform { gain }.init([fan_out, fan_in]);
+        tensor.into_data().assert_within_range(-bound..bound);
     }
 }